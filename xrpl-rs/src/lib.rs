@@ -0,0 +1,2 @@
+pub mod subscribe;
+pub mod types;
@@ -0,0 +1,340 @@
+//! Managed stream subscriptions, re-sent automatically on reconnect.
+//!
+//! `rippled`'s WebSocket API tracks `subscribe`/`unsubscribe` requests by
+//! [`RequestId`](crate::types::RequestId) (see its doc comment: reusing an id
+//! silently unsubscribes the prior request). A bare socket wrapper therefore
+//! loses every subscription across a reconnect. [`SubscriptionManager`] keeps
+//! the original subscribe requests around so they can be resent the moment
+//! the transport comes back, and fans the resulting notification frames
+//! (`ledgerClosed`, `transaction`, ...) out to one channel per subscription.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+use crate::types::{RequestId, Response};
+
+/// The streams `subscribe`/`unsubscribe` can be issued against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamKind {
+    Ledger,
+    Transactions,
+    Accounts,
+    Books,
+}
+
+/// Returns the streams a `Response.r#type` notification value is delivered
+/// to. `ledgerClosed` only ever goes to the `ledger` stream; `transaction`
+/// is shared by the `transactions`, `accounts`, and `books` streams (rippled
+/// distinguishes those by the affected accounts/offers in the payload, not
+/// by a separate `r#type`).
+fn streams_for_response_type(r#type: &str) -> &'static [StreamKind] {
+    match r#type {
+        "ledgerClosed" => &[StreamKind::Ledger],
+        "transaction" => &[
+            StreamKind::Transactions,
+            StreamKind::Accounts,
+            StreamKind::Books,
+        ],
+        _ => &[],
+    }
+}
+
+/// A backpressure signal surfaced from a `Response.warning` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backpressure {
+    /// The server reported `warning: "load"`: this client is approaching the
+    /// rate-limiting threshold and may be disconnected.
+    Load,
+}
+
+/// Errors returned by [`SubscriptionManager::unsubscribe`].
+#[derive(Debug)]
+pub enum SubscribeError<E> {
+    /// No subscription was registered under the given [`RequestId`].
+    NotFound(RequestId),
+    /// The underlying transport failed to send a request. `unsubscribe`
+    /// sends nothing to the server, so it never returns this variant.
+    Transport(E),
+}
+
+impl<E: fmt::Display> fmt::Display for SubscribeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubscribeError::NotFound(id) => write!(f, "no subscription registered for {:?}", id),
+            SubscribeError::Transport(e) => write!(f, "transport error: {}", e),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for SubscribeError<E> {}
+
+/// Sends a raw request frame to the server. Implemented by whatever owns the
+/// live WebSocket connection; `SubscriptionManager` only needs to be able to
+/// push a request out, not to own the socket itself.
+pub trait Transport {
+    type Error;
+
+    fn send(&self, request: Value) -> std::result::Result<(), Self::Error>;
+}
+
+struct Subscription {
+    stream: StreamKind,
+    request: Value,
+    sender: mpsc::UnboundedSender<Response<Value>>,
+}
+
+/// Recursively collects every string value keyed `Account`, `Destination`,
+/// `Owner`, or `RegularKey` out of a notification payload. This picks up
+/// both the top-level transacting/destination addresses and the ones
+/// nested under `meta.AffectedNodes[].*.FinalFields`.
+fn collect_addresses(value: &Value, out: &mut HashSet<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, val) in map {
+                if matches!(key.as_str(), "Account" | "Destination" | "Owner" | "RegularKey") {
+                    if let Some(s) = val.as_str() {
+                        out.insert(s.to_string());
+                    }
+                }
+                collect_addresses(val, out);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_addresses(item, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Normalizes a `TakerGets`/`TakerPays`-shaped currency amount (a plain
+/// string for XRP, or a `{currency, issuer}` object for an issued currency)
+/// into a `(currency, issuer)` key.
+fn currency_amount_key(value: &Value) -> Option<(String, String)> {
+    match value {
+        Value::String(_) => Some(("XRP".to_string(), String::new())),
+        Value::Object(map) => {
+            let currency = map.get("currency")?.as_str()?.to_string();
+            let issuer = map
+                .get("issuer")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string();
+            Some((currency, issuer))
+        }
+        _ => None,
+    }
+}
+
+/// Recursively collects the currency pairs named by any `TakerGets`/
+/// `TakerPays`/`taker_gets`/`taker_pays` field in a payload.
+fn collect_book_pairs(value: &Value, out: &mut HashSet<(String, String)>) {
+    if let Value::Object(map) = value {
+        for key in ["TakerGets", "TakerPays", "taker_gets", "taker_pays"] {
+            if let Some(pair) = map.get(key).and_then(currency_amount_key) {
+                out.insert(pair);
+            }
+        }
+        for val in map.values() {
+            collect_book_pairs(val, out);
+        }
+    } else if let Value::Array(items) = value {
+        for item in items {
+            collect_book_pairs(item, out);
+        }
+    }
+}
+
+fn response_payload(response: &Response<Value>) -> Option<&Value> {
+    match &response.result {
+        crate::types::Result::Ok(v) => Some(v),
+        crate::types::Result::Error(v) => Some(v),
+    }
+}
+
+/// Returns whether `response` is relevant to `subscription`, beyond the
+/// coarse [`StreamKind`] match already established by
+/// [`streams_for_response_type`]. `ledger`/`transactions` subscriptions take
+/// every notification for their stream; `accounts`/`books` subscriptions are
+/// narrowed further to the specific addresses/currency pairs named in their
+/// stored subscribe request, so two `accounts` subscriptions for different
+/// addresses sharing one manager don't cross-deliver each other's activity.
+///
+/// This is a best-effort match against the commonly present `Account`/
+/// `Destination`/`TakerGets`/`TakerPays`-shaped fields. If the subscribe
+/// request doesn't expose the fields needed to narrow the match (its
+/// `accounts`/`books` array is missing or yields nothing parseable), every
+/// notification for the stream is delivered. A notification that names none
+/// of the subscribed addresses/currency pairs is not delivered.
+fn matches_subscription(subscription: &Subscription, response: &Response<Value>) -> bool {
+    match subscription.stream {
+        StreamKind::Ledger | StreamKind::Transactions => true,
+        StreamKind::Accounts => {
+            let subscribed: HashSet<String> = subscription
+                .request
+                .get("accounts")
+                .and_then(Value::as_array)
+                .map(|accounts| {
+                    accounts
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default();
+            if subscribed.is_empty() {
+                return true;
+            }
+            let mut notified = HashSet::new();
+            if let Some(payload) = response_payload(response) {
+                collect_addresses(payload, &mut notified);
+            }
+            subscribed.intersection(&notified).next().is_some()
+        }
+        StreamKind::Books => {
+            let subscribed = match subscription.request.get("books").and_then(Value::as_array) {
+                Some(books) => {
+                    let mut pairs = HashSet::new();
+                    for book in books {
+                        if let Some(pair) = book.get("taker_gets").and_then(currency_amount_key) {
+                            pairs.insert(pair);
+                        }
+                        if let Some(pair) = book.get("taker_pays").and_then(currency_amount_key) {
+                            pairs.insert(pair);
+                        }
+                    }
+                    pairs
+                }
+                None => HashSet::new(),
+            };
+            if subscribed.is_empty() {
+                return true;
+            }
+            let mut notified = HashSet::new();
+            if let Some(payload) = response_payload(response) {
+                collect_book_pairs(payload, &mut notified);
+            }
+            subscribed.intersection(&notified).next().is_some()
+        }
+    }
+}
+
+/// Tracks live stream subscriptions and replays them across reconnects.
+pub struct SubscriptionManager<T: Transport> {
+    transport: Arc<T>,
+    subscriptions: Mutex<HashMap<RequestId, Subscription>>,
+}
+
+impl<T: Transport> SubscriptionManager<T> {
+    pub fn new(transport: Arc<T>) -> Self {
+        Self {
+            transport,
+            subscriptions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Registers a subscription and sends its `subscribe` request, returning
+    /// a channel that yields every notification frame delivered for it.
+    pub fn subscribe(
+        &self,
+        id: RequestId,
+        stream: StreamKind,
+        request: Value,
+    ) -> std::result::Result<mpsc::UnboundedReceiver<Response<Value>>, T::Error> {
+        self.transport.send(request.clone())?;
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        self.subscriptions.lock().unwrap().insert(
+            id,
+            Subscription {
+                stream,
+                request,
+                sender,
+            },
+        );
+        Ok(receiver)
+    }
+
+    /// Drops a subscription. Per the `subscribe`/`unsubscribe` protocol,
+    /// issuing a new `subscribe` with the same [`RequestId`] has the same
+    /// effect server-side; this just stops local delivery and forgets the
+    /// stored request so it isn't replayed on the next reconnect.
+    pub fn unsubscribe(&self, id: &RequestId) -> std::result::Result<(), SubscribeError<T::Error>> {
+        self.subscriptions
+            .lock()
+            .unwrap()
+            .remove(id)
+            .ok_or_else(|| SubscribeError::NotFound(id.clone()))?;
+        Ok(())
+    }
+
+    /// Re-sends every stored subscription request. Call this after the
+    /// underlying transport reconnects so consumers keep receiving frames
+    /// without re-subscribing by hand.
+    pub fn resubscribe_all(&self) -> std::result::Result<(), T::Error> {
+        for subscription in self.subscriptions.lock().unwrap().values() {
+            self.transport.send(subscription.request.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Routes an incoming frame to the channel(s) it belongs to and returns
+    /// any backpressure signal it carried.
+    ///
+    /// A response with an `id` is a direct reply to the `subscribe` request
+    /// that opened the stream and is routed to that subscription alone. A
+    /// response with no `id` is an asynchronous notification: it's first
+    /// narrowed by its `r#type` (see [`streams_for_response_type`]) to the
+    /// stream(s) it could belong to, then by [`matches_subscription`] to the
+    /// specific addresses/books each of those subscriptions actually asked
+    /// for, so sibling `accounts`/`books` subscriptions don't see each
+    /// other's activity.
+    ///
+    /// Stale subscriptions whose receiver has been dropped are pruned.
+    pub fn dispatch(&self, response: Response<Value>) -> Option<Backpressure> {
+        let backpressure = match response.warning.as_deref() {
+            Some("load") => Some(Backpressure::Load),
+            _ => None,
+        };
+
+        if let Some(id) = response.id.clone() {
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            if let Some(subscription) = subscriptions.get(&id) {
+                if subscription.sender.send(response).is_err() {
+                    subscriptions.remove(&id);
+                }
+            }
+        } else {
+            let streams = response.r#type.as_deref().map(streams_for_response_type).unwrap_or(&[]);
+            let mut subscriptions = self.subscriptions.lock().unwrap();
+            subscriptions.retain(|_, subscription| {
+                if !streams.contains(&subscription.stream) {
+                    return true;
+                }
+                if !matches_subscription(subscription, &response) {
+                    return true;
+                }
+                subscription.sender.send(response.clone()).is_ok()
+            });
+        }
+
+        backpressure
+    }
+
+    /// Returns the set of streams with at least one active subscription.
+    pub fn active_streams(&self) -> Vec<StreamKind> {
+        self.subscriptions
+            .lock()
+            .unwrap()
+            .values()
+            .map(|s| s.stream)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
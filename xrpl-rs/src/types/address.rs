@@ -0,0 +1,352 @@
+//! XRPL account addresses: classic (`r...`) and X-addresses (`X...`).
+//!
+//! Both formats are base58check-encoded over the Ripple alphabet, with the
+//! checksum being the first 4 bytes of `SHA256(SHA256(payload))`.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+// The 58 characters rippled uses in place of the Bitcoin base58 alphabet.
+// Verified against the two best-known XRPL addresses, `ACCOUNT_ZERO`
+// (`rrrrrrrrrrrrrrrrrrrrrhoLvTp`, the AccountID of all zero bytes) and
+// `ACCOUNT_ONE` (`rrrrrrrrrrrrrrrrrrrrBZbvji`, 19 zero bytes then `0x01`) —
+// see the `known_account_*` tests below.
+const RIPPLE_ALPHABET: &[u8] = b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+
+/// Length of the raw AccountID payload carried by every address.
+pub const ACCOUNT_ID_LEN: usize = 20;
+
+/// Type prefix byte for a classic address (`r...`).
+const CLASSIC_ADDRESS_PREFIX: u8 = 0x00;
+
+/// Type prefix bytes for an X-address, followed by a one-byte flag
+/// (tagged/untagged) and the 20-byte AccountID and 8-byte tag.
+const X_ADDRESS_PREFIX: [u8; 2] = [0x05, 0x44];
+
+/// The 20-byte account identifier carried by every XRPL address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountId(pub [u8; ACCOUNT_ID_LEN]);
+
+impl AccountId {
+    /// Returns the raw 20-byte AccountID.
+    pub fn as_bytes(&self) -> &[u8; ACCOUNT_ID_LEN] {
+        &self.0
+    }
+}
+
+/// Errors that can occur while parsing an [`Address`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressError {
+    /// A character outside the Ripple base58 alphabet was encountered.
+    InvalidCharacter(char),
+    /// The decoded payload's checksum did not match the trailing 4 bytes.
+    InvalidChecksum,
+    /// The decoded payload was not the expected length for its type prefix,
+    /// or an X-address's 8-byte tag field held a value wider than 4 bytes.
+    InvalidLength { expected: usize, found: usize },
+    /// The leading type prefix byte(s) did not match a known address format.
+    InvalidPrefix,
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddressError::InvalidCharacter(c) => {
+                write!(f, "invalid base58 character: {:?}", c)
+            }
+            AddressError::InvalidChecksum => write!(f, "invalid address checksum"),
+            AddressError::InvalidLength { expected, found } => write!(
+                f,
+                "invalid address payload length: expected {}, found {}",
+                expected, found
+            ),
+            AddressError::InvalidPrefix => write!(f, "unrecognized address type prefix"),
+        }
+    }
+}
+
+impl std::error::Error for AddressError {}
+
+fn base58_decode(input: &str) -> Result<Vec<u8>, AddressError> {
+    let mut digits: Vec<u8> = vec![0];
+    for c in input.chars() {
+        let value = RIPPLE_ALPHABET
+            .iter()
+            .position(|&b| b == c as u8)
+            .ok_or(AddressError::InvalidCharacter(c))? as u32;
+        let mut carry = value;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) * 58;
+            *digit = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            digits.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    // Leading '1' (alphabet index 0, i.e. 'r') characters encode leading zero bytes.
+    let leading_zeros = input
+        .chars()
+        .take_while(|&c| c as u8 == RIPPLE_ALPHABET[0])
+        .count();
+
+    let mut bytes = vec![0u8; leading_zeros];
+    bytes.extend(digits.iter().rev());
+    Ok(bytes)
+}
+
+fn base58_encode(input: &[u8]) -> String {
+    let mut digits: Vec<u8> = vec![0];
+    for &byte in input {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let leading_zeros = input.iter().take_while(|&&b| b == 0).count();
+    let mut out: Vec<u8> = std::iter::repeat_n(RIPPLE_ALPHABET[0], leading_zeros).collect();
+    out.extend(digits.iter().rev().map(|&d| RIPPLE_ALPHABET[d as usize]));
+    String::from_utf8(out).expect("alphabet is ASCII")
+}
+
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    let first = Sha256::digest(data);
+    let second = Sha256::digest(first);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&second);
+    out
+}
+
+fn base58check_decode(input: &str) -> Result<Vec<u8>, AddressError> {
+    let raw = base58_decode(input)?;
+    if raw.len() < 4 {
+        return Err(AddressError::InvalidLength {
+            expected: 4,
+            found: raw.len(),
+        });
+    }
+    let (payload, checksum) = raw.split_at(raw.len() - 4);
+    let expected = &double_sha256(payload)[..4];
+    if expected != checksum {
+        return Err(AddressError::InvalidChecksum);
+    }
+    Ok(payload.to_vec())
+}
+
+fn base58check_encode(payload: &[u8]) -> String {
+    let checksum = &double_sha256(payload)[..4];
+    let mut full = payload.to_vec();
+    full.extend_from_slice(checksum);
+    base58_encode(&full)
+}
+
+/// A validated XRPL account address.
+///
+/// Parsing (via [`Address::from_classic`], [`Address::from_x_address`], or
+/// `TryFrom<&str>`) checks the base58check checksum and payload length, so a
+/// value of this type is guaranteed to wrap a well-formed 20-byte AccountID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Address(AccountId);
+
+impl Address {
+    /// Builds an `Address` directly from a raw 20-byte AccountID.
+    pub fn from_account_id(account_id: AccountId) -> Self {
+        Self(account_id)
+    }
+
+    /// Returns the underlying 20-byte AccountID.
+    pub fn account_id(&self) -> AccountId {
+        self.0
+    }
+
+    /// Parses a classic (`r...`) address.
+    pub fn from_classic(s: &str) -> Result<Self, AddressError> {
+        let payload = base58check_decode(s)?;
+        if payload.len() != 1 + ACCOUNT_ID_LEN {
+            return Err(AddressError::InvalidLength {
+                expected: 1 + ACCOUNT_ID_LEN,
+                found: payload.len(),
+            });
+        }
+        if payload[0] != CLASSIC_ADDRESS_PREFIX {
+            return Err(AddressError::InvalidPrefix);
+        }
+        let mut account_id = [0u8; ACCOUNT_ID_LEN];
+        account_id.copy_from_slice(&payload[1..]);
+        Ok(Self(AccountId(account_id)))
+    }
+
+    /// Renders this address as a classic (`r...`) address.
+    pub fn to_classic(&self) -> String {
+        let mut payload = Vec::with_capacity(1 + ACCOUNT_ID_LEN);
+        payload.push(CLASSIC_ADDRESS_PREFIX);
+        payload.extend_from_slice(&self.0 .0);
+        base58check_encode(&payload)
+    }
+
+    /// Parses an X-address, returning the AccountID and embedded destination tag.
+    pub fn from_x_address(s: &str) -> Result<(AccountId, Option<u32>), AddressError> {
+        let payload = base58check_decode(s)?;
+        // prefix (2) + flag (1) + AccountID (20) + tag (8)
+        let expected = X_ADDRESS_PREFIX.len() + 1 + ACCOUNT_ID_LEN + 8;
+        if payload.len() != expected {
+            return Err(AddressError::InvalidLength {
+                expected,
+                found: payload.len(),
+            });
+        }
+        if payload[0..2] != X_ADDRESS_PREFIX {
+            return Err(AddressError::InvalidPrefix);
+        }
+        let has_tag = payload[2] != 0;
+        let mut account_id = [0u8; ACCOUNT_ID_LEN];
+        account_id.copy_from_slice(&payload[3..3 + ACCOUNT_ID_LEN]);
+        let tag_bytes = &payload[3 + ACCOUNT_ID_LEN..];
+        let tag = if has_tag {
+            let raw = u64::from_le_bytes(tag_bytes.try_into().expect("8 bytes"));
+            Some(u32::try_from(raw).map_err(|_| AddressError::InvalidLength {
+                expected: 4,
+                found: 8,
+            })?)
+        } else {
+            None
+        };
+        Ok((AccountId(account_id), tag))
+    }
+
+    /// Renders this address as an X-address, optionally embedding a destination tag.
+    pub fn to_x_address(&self, tag: Option<u32>) -> String {
+        let mut payload = Vec::with_capacity(X_ADDRESS_PREFIX.len() + 1 + ACCOUNT_ID_LEN + 8);
+        payload.extend_from_slice(&X_ADDRESS_PREFIX);
+        payload.push(if tag.is_some() { 1 } else { 0 });
+        payload.extend_from_slice(&self.0 .0);
+        payload.extend_from_slice(&(tag.unwrap_or(0) as u64).to_le_bytes());
+        base58check_encode(&payload)
+    }
+}
+
+impl TryFrom<&str> for Address {
+    type Error = AddressError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.starts_with('r') {
+            Address::from_classic(value)
+        } else {
+            let (account_id, _tag) = Address::from_x_address(value)?;
+            Ok(Address(account_id))
+        }
+    }
+}
+
+impl TryFrom<String> for Address {
+    type Error = AddressError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Address::try_from(value.as_str())
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_classic())
+    }
+}
+
+impl Serialize for Address {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_classic())
+    }
+}
+
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Address::try_from(s.as_str()).map_err(D::Error::custom)
+    }
+}
+
+impl Default for Address {
+    fn default() -> Self {
+        Address(AccountId([0u8; ACCOUNT_ID_LEN]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `ACCOUNT_ZERO`: the classic address of the all-zero AccountID.
+    const ACCOUNT_ZERO: &str = "rrrrrrrrrrrrrrrrrrrrrhoLvTp";
+    /// `ACCOUNT_ONE`: the classic address of 19 zero bytes followed by `0x01`.
+    const ACCOUNT_ONE: &str = "rrrrrrrrrrrrrrrrrrrrBZbvji";
+
+    #[test]
+    fn known_account_zero_decodes() {
+        let address = Address::from_classic(ACCOUNT_ZERO).unwrap();
+        assert_eq!(address.account_id().0, [0u8; ACCOUNT_ID_LEN]);
+    }
+
+    #[test]
+    fn known_account_one_decodes() {
+        let address = Address::from_classic(ACCOUNT_ONE).unwrap();
+        let mut expected = [0u8; ACCOUNT_ID_LEN];
+        expected[ACCOUNT_ID_LEN - 1] = 1;
+        assert_eq!(address.account_id().0, expected);
+    }
+
+    #[test]
+    fn known_bad_checksum_is_rejected() {
+        // Last character changed, so the trailing checksum byte no longer matches.
+        let corrupted = "rrrrrrrrrrrrrrrrrrrrrhoLvTq";
+        assert_eq!(
+            Address::from_classic(corrupted),
+            Err(AddressError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn classic_address_round_trips() {
+        let account_id = AccountId([7u8; ACCOUNT_ID_LEN]);
+        let address = Address::from_account_id(account_id);
+        let encoded = address.to_classic();
+        let decoded = Address::from_classic(&encoded).unwrap();
+        assert_eq!(decoded, address);
+    }
+
+    #[test]
+    fn x_address_round_trips_with_tag() {
+        let account_id = AccountId([9u8; ACCOUNT_ID_LEN]);
+        let address = Address::from_account_id(account_id);
+        let encoded = address.to_x_address(Some(12345));
+        let (decoded_id, tag) = Address::from_x_address(&encoded).unwrap();
+        assert_eq!(decoded_id, account_id);
+        assert_eq!(tag, Some(12345));
+    }
+
+    #[test]
+    fn invalid_character_is_rejected() {
+        assert_eq!(
+            Address::from_classic("r0OIl"),
+            Err(AddressError::InvalidCharacter('0'))
+        );
+    }
+}
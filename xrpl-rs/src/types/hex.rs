@@ -0,0 +1,373 @@
+//! Length-checked hex-encoded byte blobs used throughout ledger data: 256-bit
+//! hashes, signing public keys, and arbitrary-length byte strings.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Errors that can occur while decoding a hex-encoded field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HexError {
+    /// The string contained a non-hex-digit character.
+    InvalidDigit(char),
+    /// The string had an odd number of hex digits.
+    OddLength,
+    /// The decoded bytes were not the expected length.
+    InvalidLength { expected: usize, found: usize },
+    /// The decoded bytes did not start with a recognized type-prefix byte.
+    InvalidTypePrefix(u8),
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HexError::InvalidDigit(c) => write!(f, "invalid hex digit: {:?}", c),
+            HexError::OddLength => write!(f, "hex string has an odd number of digits"),
+            HexError::InvalidLength { expected, found } => {
+                write!(f, "expected {} bytes, found {}", expected, found)
+            }
+            HexError::InvalidTypePrefix(b) => {
+                write!(f, "unrecognized type-prefix byte: {:#04x}", b)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HexError {}
+
+/// Helpers for `#[serde(with = "serde_hex")]` on a raw `Vec<u8>` field, and
+/// the building blocks used by the typed newtypes in this module.
+pub mod serde_hex {
+    use super::*;
+
+    /// Encodes `bytes` as uppercase hex, matching rippled's wire format.
+    pub fn encode(bytes: &[u8]) -> String {
+        let mut s = String::with_capacity(bytes.len() * 2);
+        for b in bytes {
+            s.push_str(&format!("{:02X}", b));
+        }
+        s
+    }
+
+    /// Decodes an uppercase or lowercase hex string into bytes.
+    pub fn decode(s: &str) -> Result<Vec<u8>, HexError> {
+        if !s.len().is_multiple_of(2) {
+            return Err(HexError::OddLength);
+        }
+        let mut bytes = Vec::with_capacity(s.len() / 2);
+        let chars: Vec<char> = s.chars().collect();
+        for pair in chars.chunks(2) {
+            let hi = pair[0].to_digit(16).ok_or(HexError::InvalidDigit(pair[0]))?;
+            let lo = pair[1].to_digit(16).ok_or(HexError::InvalidDigit(pair[1]))?;
+            bytes.push(((hi << 4) | lo) as u8);
+        }
+        Ok(bytes)
+    }
+
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&encode(bytes))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        decode(&s).map_err(D::Error::custom)
+    }
+}
+
+/// A 32-byte hash, such as a transaction or ledger object ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Hash256([u8; 32]);
+
+impl Hash256 {
+    /// Returns the raw 32 bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for Hash256 {
+    type Error = HexError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let bytes = serde_hex::decode(value)?;
+        if bytes.len() != 32 {
+            return Err(HexError::InvalidLength {
+                expected: 32,
+                found: bytes.len(),
+            });
+        }
+        let mut array = [0u8; 32];
+        array.copy_from_slice(&bytes);
+        Ok(Self(array))
+    }
+}
+
+impl fmt::Display for Hash256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&serde_hex::encode(&self.0))
+    }
+}
+
+impl Serialize for Hash256 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&serde_hex::encode(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Hash256 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Hash256::try_from(s.as_str()).map_err(D::Error::custom)
+    }
+}
+
+/// A 16-byte MD5 hash, such as the `EmailHash` field used to look up Gravatar avatars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct EmailHash([u8; 16]);
+
+impl EmailHash {
+    /// Returns the raw 16 bytes.
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for EmailHash {
+    type Error = HexError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let bytes = serde_hex::decode(value)?;
+        if bytes.len() != 16 {
+            return Err(HexError::InvalidLength {
+                expected: 16,
+                found: bytes.len(),
+            });
+        }
+        let mut array = [0u8; 16];
+        array.copy_from_slice(&bytes);
+        Ok(Self(array))
+    }
+}
+
+impl fmt::Display for EmailHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&serde_hex::encode(&self.0))
+    }
+}
+
+impl Serialize for EmailHash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&serde_hex::encode(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailHash {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        EmailHash::try_from(s.as_str()).map_err(D::Error::custom)
+    }
+}
+
+/// A 33-byte message-signing public key: a one-byte type prefix (`0x02`/`0x03`
+/// for secp256k1, `0xED` for Ed25519) followed by 32 bytes of key material.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageKey([u8; 33]);
+
+impl MessageKey {
+    /// Returns the raw 33 bytes, including the type-prefix byte.
+    pub fn as_bytes(&self) -> &[u8; 33] {
+        &self.0
+    }
+
+    /// Returns the one-byte key-type prefix (`0x02`, `0x03`, or `0xED`).
+    pub fn key_type_prefix(&self) -> u8 {
+        self.0[0]
+    }
+}
+
+impl TryFrom<&str> for MessageKey {
+    type Error = HexError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let bytes = serde_hex::decode(value)?;
+        if bytes.len() != 33 {
+            return Err(HexError::InvalidLength {
+                expected: 33,
+                found: bytes.len(),
+            });
+        }
+        match bytes[0] {
+            0x02 | 0x03 | 0xED => {}
+            other => return Err(HexError::InvalidTypePrefix(other)),
+        }
+        let mut array = [0u8; 33];
+        array.copy_from_slice(&bytes);
+        Ok(Self(array))
+    }
+}
+
+impl fmt::Display for MessageKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&serde_hex::encode(&self.0))
+    }
+}
+
+impl Serialize for MessageKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&serde_hex::encode(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        MessageKey::try_from(s.as_str()).map_err(D::Error::custom)
+    }
+}
+
+/// An arbitrary-length hex-encoded byte blob, such as a `Domain` field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Hex(pub Vec<u8>);
+
+impl Hex {
+    /// Returns the raw decoded bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for Hex {
+    type Error = HexError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Self(serde_hex::decode(value)?))
+    }
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&serde_hex::encode(&self.0))
+    }
+}
+
+impl Serialize for Hex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&serde_hex::encode(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Hex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Hex::try_from(s.as_str()).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash256_decodes_32_bytes() {
+        let hex = "00".repeat(32);
+        let hash = Hash256::try_from(hex.as_str()).unwrap();
+        assert_eq!(hash.as_bytes(), &[0u8; 32]);
+    }
+
+    #[test]
+    fn hash256_rejects_wrong_length() {
+        let hex = "00".repeat(31);
+        assert_eq!(
+            Hash256::try_from(hex.as_str()),
+            Err(HexError::InvalidLength {
+                expected: 32,
+                found: 31
+            })
+        );
+    }
+
+    #[test]
+    fn email_hash_decodes_16_bytes() {
+        let hex = "AB".repeat(16);
+        let hash = EmailHash::try_from(hex.as_str()).unwrap();
+        assert_eq!(hash.as_bytes(), &[0xABu8; 16]);
+    }
+
+    #[test]
+    fn email_hash_rejects_wrong_length() {
+        let hex = "AB".repeat(20);
+        assert_eq!(
+            EmailHash::try_from(hex.as_str()),
+            Err(HexError::InvalidLength {
+                expected: 16,
+                found: 20
+            })
+        );
+    }
+
+    #[test]
+    fn message_key_accepts_known_type_prefixes() {
+        for prefix in [0x02u8, 0x03, 0xED] {
+            let mut bytes = vec![prefix];
+            bytes.extend([0u8; 32]);
+            let hex = serde_hex::encode(&bytes);
+            assert!(MessageKey::try_from(hex.as_str()).is_ok());
+        }
+    }
+
+    #[test]
+    fn message_key_rejects_unknown_type_prefix() {
+        let mut bytes = vec![0x04u8];
+        bytes.extend([0u8; 32]);
+        let hex = serde_hex::encode(&bytes);
+        assert_eq!(
+            MessageKey::try_from(hex.as_str()),
+            Err(HexError::InvalidTypePrefix(0x04))
+        );
+    }
+
+    #[test]
+    fn decode_rejects_odd_length() {
+        assert_eq!(serde_hex::decode("ABC"), Err(HexError::OddLength));
+    }
+
+    #[test]
+    fn decode_rejects_invalid_digit() {
+        assert_eq!(
+            serde_hex::decode("ZZ"),
+            Err(HexError::InvalidDigit('Z'))
+        );
+    }
+}
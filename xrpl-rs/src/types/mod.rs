@@ -1,21 +1,24 @@
 pub mod account;
+pub mod address;
 pub mod fee;
+pub mod hex;
 pub mod submit;
 
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 use std::num::ParseIntError;
 
 use serde;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-/// An address used to identify an account.
-pub type Address = String;
+pub use address::{AccountId, Address, AddressError};
+pub use hex::{EmailHash, Hash256, Hex, HexError, MessageKey};
 
 /// A Marker can be used to paginate the server response. It's content is intentionally undefined. Each server can define a marker as desired.
 pub type Marker = Value;
 
-pub type H256 = String;
+/// A 32-byte hash, hex-encoded on the wire. Kept as an alias of [`Hash256`] for backwards compatibility.
+pub type H256 = Hash256;
 
 /// Unique request id.
 ///
@@ -90,14 +93,90 @@ pub struct SignerList {
 #[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct SignerEntry {
     #[serde(rename = "Account")]
-    pub account: String,
+    pub account: Address,
     #[serde(rename = "SignerWeight")]
     pub signer_weight: u16,
 }
 
-#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Default, Clone)]
+/// Errors produced while constructing or deserializing amount types such as [`Drops`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmountError {
+    /// The amount exceeds the 100-billion-XRP total supply.
+    ExceedsMaxSupply { value: u64, max: u64 },
+    /// The amount of XRP was negative; drops cannot represent a negative amount.
+    NegativeAmount,
+}
+
+impl std::fmt::Display for AmountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AmountError::ExceedsMaxSupply { value, max } => write!(
+                f,
+                "amount {} drops exceeds the maximum possible supply of {} drops",
+                value, max
+            ),
+            AmountError::NegativeAmount => write!(f, "drops amount cannot be negative"),
+        }
+    }
+}
+
+impl std::error::Error for AmountError {}
+
+/// An amount of XRP, denominated in drops (1 XRP = 1,000,000 drops).
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Default, Clone, Copy)]
 pub struct Drops(u64);
 
+impl Drops {
+    /// The maximum number of drops that can ever exist: 100 billion XRP.
+    pub const MAX: Drops = Drops(100_000_000_000_000_000);
+
+    /// Constructs a `Drops` value, rejecting amounts above [`Drops::MAX`].
+    pub fn new(value: u64) -> std::result::Result<Self, AmountError> {
+        if value > Self::MAX.0 {
+            return Err(AmountError::ExceedsMaxSupply {
+                value,
+                max: Self::MAX.0,
+            });
+        }
+        Ok(Self(value))
+    }
+
+    /// Converts an amount of XRP (1 XRP = 1,000,000 drops) into drops, rejecting
+    /// negative amounts and amounts above [`Drops::MAX`].
+    pub fn from_xrp(xrp: f64) -> std::result::Result<Self, AmountError> {
+        if xrp < 0.0 {
+            return Err(AmountError::NegativeAmount);
+        }
+        Self::new((xrp * 1_000_000.0).round() as u64)
+    }
+
+    /// Converts this amount of drops into XRP.
+    pub fn as_xrp(&self) -> f64 {
+        self.0 as f64 / 1_000_000.0
+    }
+
+    /// Adds two drops amounts, returning `None` on overflow or if the result would exceed [`Drops::MAX`].
+    pub fn checked_add(&self, other: Drops) -> Option<Drops> {
+        self.0
+            .checked_add(other.0)
+            .filter(|&v| v <= Self::MAX.0)
+            .map(Drops)
+    }
+
+    /// Subtracts two drops amounts, returning `None` on underflow.
+    pub fn checked_sub(&self, other: Drops) -> Option<Drops> {
+        self.0.checked_sub(other.0).map(Drops)
+    }
+
+    /// Multiplies a drops amount by a scalar, returning `None` on overflow or if the result would exceed [`Drops::MAX`].
+    pub fn checked_mul(&self, rhs: u64) -> Option<Drops> {
+        self.0
+            .checked_mul(rhs)
+            .filter(|&v| v <= Self::MAX.0)
+            .map(Drops)
+    }
+}
+
 impl Serialize for Drops {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -129,25 +208,47 @@ impl<'de> serde::de::Visitor<'de> for DropsVisitor {
     where
         E: serde::de::Error,
     {
-        Ok(value
-            .try_into()
-            .map_err(|e| serde::de::Error::custom(format!("{:?}", e)))?)
+        let raw: u64 = value
+            .parse()
+            .map_err(|e: ParseIntError| serde::de::Error::custom(format!("{:?}", e)))?;
+        Drops::new(raw).map_err(serde::de::Error::custom)
     }
 }
 
+/// Errors produced while parsing a [`Drops`] value from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DropsParseError {
+    /// The string was not a valid unsigned integer.
+    InvalidInt(ParseIntError),
+    /// The parsed integer failed [`Drops::new`]'s supply-ceiling check.
+    Amount(AmountError),
+}
+
+impl std::fmt::Display for DropsParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DropsParseError::InvalidInt(e) => write!(f, "{}", e),
+            DropsParseError::Amount(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for DropsParseError {}
+
 impl TryFrom<String> for Drops {
-    type Error = ParseIntError;
+    type Error = DropsParseError;
 
     fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
-        Ok(Self(value.parse()?))
+        Drops::try_from(value.as_str())
     }
 }
 
 impl TryFrom<&str> for Drops {
-    type Error = ParseIntError;
+    type Error = DropsParseError;
 
     fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
-        Ok(Self(value.parse()?))
+        let raw: u64 = value.parse().map_err(DropsParseError::InvalidInt)?;
+        Drops::new(raw).map_err(DropsParseError::Amount)
     }
 }
 
@@ -160,7 +261,7 @@ pub enum CurrencyAmount {
 
 impl Default for CurrencyAmount {
     fn default() -> Self {
-        return Self::XRP(Drops(0u64));
+        Self::XRP(Drops(0u64))
     }
 }
 
@@ -185,17 +286,99 @@ pub struct TransactionEntryResponse {
     pub ledger_hash: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq)]
-#[serde(tag = "LedgerEntryType")]
+#[derive(Debug, Eq, PartialEq)]
 pub enum LedgerEntry {
-    Unknown,
     AccountRoot(AccountRoot),
     Check(Check),
+    Offer(Offer),
+    RippleState(RippleState),
+    Escrow(Escrow),
+    PayChannel(PayChannel),
+    DirectoryNode(DirectoryNode),
+    SignerList(SignerListEntry),
+    Ticket(Ticket),
+    DepositPreauth(DepositPreauth),
+    /// Any ledger object type not yet modeled above. The raw JSON is kept
+    /// rather than discarded, so forward-compatibility with new object
+    /// types added to rippled is lossless.
+    Unknown(Value),
 }
 
 impl Default for LedgerEntry {
     fn default() -> Self {
-        Self::Unknown
+        Self::Unknown(Value::Null)
+    }
+}
+
+// Hand-written rather than `#[derive(Serialize, Deserialize)]`: a derived
+// internally tagged enum only matches a `LedgerEntryType` equal to one of
+// the variant names above, hard-errors on anything else (e.g.
+// `NFTokenPage`) on deserialize, and can't serialize a data-carrying
+// `Unknown` variant at all. Buffer the object as a `Value` on the way in,
+// dispatch on its tag, and fall back to `Unknown` ourselves; on the way out,
+// re-insert the tag for known variants and re-emit `Unknown`'s `Value`
+// as-is, so deserialize-then-reserialize is lossless.
+impl Serialize for LedgerEntry {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        macro_rules! tagged {
+            ($name:literal, $inner:expr) => {{
+                let mut value = serde_json::to_value($inner).map_err(serde::ser::Error::custom)?;
+                if let Value::Object(ref mut map) = value {
+                    map.insert("LedgerEntryType".to_string(), Value::String($name.to_string()));
+                }
+                value.serialize(serializer)
+            }};
+        }
+
+        match self {
+            LedgerEntry::AccountRoot(inner) => tagged!("AccountRoot", inner),
+            LedgerEntry::Check(inner) => tagged!("Check", inner),
+            LedgerEntry::Offer(inner) => tagged!("Offer", inner),
+            LedgerEntry::RippleState(inner) => tagged!("RippleState", inner),
+            LedgerEntry::Escrow(inner) => tagged!("Escrow", inner),
+            LedgerEntry::PayChannel(inner) => tagged!("PayChannel", inner),
+            LedgerEntry::DirectoryNode(inner) => tagged!("DirectoryNode", inner),
+            LedgerEntry::SignerList(inner) => tagged!("SignerList", inner),
+            LedgerEntry::Ticket(inner) => tagged!("Ticket", inner),
+            LedgerEntry::DepositPreauth(inner) => tagged!("DepositPreauth", inner),
+            LedgerEntry::Unknown(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LedgerEntry {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let tag = value.get("LedgerEntryType").and_then(Value::as_str);
+
+        macro_rules! variant {
+            ($name:literal, $variant:ident, $ty:ty) => {
+                if tag == Some($name) {
+                    let inner: $ty =
+                        serde_json::from_value(value).map_err(serde::de::Error::custom)?;
+                    return Ok(LedgerEntry::$variant(inner));
+                }
+            };
+        }
+
+        variant!("AccountRoot", AccountRoot, AccountRoot);
+        variant!("Check", Check, Check);
+        variant!("Offer", Offer, Offer);
+        variant!("RippleState", RippleState, RippleState);
+        variant!("Escrow", Escrow, Escrow);
+        variant!("PayChannel", PayChannel, PayChannel);
+        variant!("DirectoryNode", DirectoryNode, DirectoryNode);
+        variant!("SignerList", SignerList, SignerListEntry);
+        variant!("Ticket", Ticket, Ticket);
+        variant!("DepositPreauth", DepositPreauth, DepositPreauth);
+
+        Ok(LedgerEntry::Unknown(value))
     }
 }
 
@@ -219,14 +402,14 @@ pub struct AccountRoot {
     pub sequence: u32,
     /// (Optional) The identifying hash of the transaction most recently sent by this account. This field must be enabled to use the AccountTxnID transaction field. To enable it, send an AccountSet transaction with the asfAccountTxnID flag enabled.
     pub account_txn_id: Option<H256>,
-    /// (Optional) A domain associated with this account. In JSON, this is the hexadecimal for the ASCII representation of the domain. Cannot be more than 256 bytes in length.
-    pub domain: Option<String>,
+    /// (Optional) A domain associated with this account. In JSON, this is the hexadecimal for the ASCII representation of the domain. Cannot be more than 256 bytes in length. Use [`AccountRoot::domain_string`] to decode it.
+    pub domain: Option<Hex>,
     /// (Optional) The md5 hash of an email address. Clients can use this to look up an avatar through services such as Gravatar .
-    pub email_hash: Option<H256>,
+    pub email_hash: Option<EmailHash>,
     /// (Optional) A public key that may be used to send encrypted messages to this account. In JSON, uses hexadecimal. Must be exactly 33 bytes, with the first byte indicating the key type: 0x02 or 0x03 for secp256k1 keys, 0xED for Ed25519 keys.
-    pub message_key: Option<String>,
+    pub message_key: Option<MessageKey>,
     /// (Optional) The address of a key pair that can be used to sign transactions for this account instead of the master key. Use a SetRegularKey transaction to change this value.
-    pub regular_key: Option<String>,
+    pub regular_key: Option<Address>,
     /// (Optional) How many Tickets this account owns in the ledger. This is updated automatically to ensure that the account stays within the hard limit of 250 Tickets at a time. This field is omitted if the account has zero Tickets. (Added by the TicketBatch amendment )
     pub ticket_count: Option<u32>,
     /// (Optional) How many significant digits to use for exchange rates of Offers involving currencies issued by this address. Valid values are 3 to 15, inclusive. (Added by the TickSize amendment.)
@@ -235,6 +418,15 @@ pub struct AccountRoot {
     pub transfer_rate: Option<u32>,
 }
 
+impl AccountRoot {
+    /// Decodes `domain` from hex-encoded ASCII into a `String`, if present.
+    pub fn domain_string(&self) -> Option<std::result::Result<String, std::string::FromUtf8Error>> {
+        self.domain
+            .as_ref()
+            .map(|hex| String::from_utf8(hex.as_bytes().to_vec()))
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Check {
@@ -244,4 +436,310 @@ pub struct Check {
     pub destination: Address,
     /// A bit-map of boolean flags enabled for this account.
     pub flags: u32,
-}
\ No newline at end of file
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Offer {
+    /// The address of the account that owns this Offer.
+    pub account: Address,
+    /// A bit-map of boolean flags enabled for this Offer.
+    pub flags: u32,
+    /// The Sequence value of the OfferCreate transaction that created this Offer. Used together with the Account to identify this Offer.
+    pub sequence: u32,
+    /// The amount the account accepting the Offer provides.
+    pub taker_pays: CurrencyAmount,
+    /// The amount the account accepting the Offer receives.
+    pub taker_gets: CurrencyAmount,
+    /// The ledger index of the offer directory that links to this Offer.
+    pub book_directory: H256,
+    /// A hint indicating which page of the offer directory links to this Offer, in case the directory consists of multiple pages.
+    pub book_node: String,
+    /// A hint indicating which page of the owner directory links to this object, in case the directory consists of multiple pages.
+    pub owner_node: String,
+    /// The identifying hash of the transaction that most recently modified this object.
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: H256,
+    /// The index of the ledger that contains the transaction that most recently modified this object.
+    pub previous_txn_lgr_seq: u32,
+    /// (Optional) The time this Offer expires, in seconds since the Ripple Epoch.
+    pub expiration: Option<u32>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RippleState {
+    /// A bit-map of boolean flags enabled for this trust line.
+    pub flags: u32,
+    /// The balance of the trust line, from the perspective of the low account. A negative balance indicates the low account owes the high account.
+    pub balance: CurrencyAmount,
+    /// The limit the low account has set on the trust line.
+    pub low_limit: CurrencyAmount,
+    /// The limit the high account has set on the trust line.
+    pub high_limit: CurrencyAmount,
+    /// The identifying hash of the transaction that most recently modified this object.
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: H256,
+    /// The index of the ledger that contains the transaction that most recently modified this object.
+    pub previous_txn_lgr_seq: u32,
+    /// (Optional) A hint indicating which page of the low account's owner directory links to this object, in case the directory consists of multiple pages.
+    pub low_node: Option<String>,
+    /// (Optional) A hint indicating which page of the high account's owner directory links to this object, in case the directory consists of multiple pages.
+    pub high_node: Option<String>,
+    /// (Optional) The inbound quality set by the low account, as an integer in the implied ratio LowQualityIn:1,000,000,000.
+    pub low_quality_in: Option<u32>,
+    /// (Optional) The outbound quality set by the low account, as an integer in the implied ratio LowQualityOut:1,000,000,000.
+    pub low_quality_out: Option<u32>,
+    /// (Optional) The inbound quality set by the high account, as an integer in the implied ratio HighQualityIn:1,000,000,000.
+    pub high_quality_in: Option<u32>,
+    /// (Optional) The outbound quality set by the high account, as an integer in the implied ratio HighQualityOut:1,000,000,000.
+    pub high_quality_out: Option<u32>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Escrow {
+    /// The address of the owner (sender) of this Escrow.
+    pub account: Address,
+    /// The destination address where the Escrow will be paid, if successful.
+    pub destination: Address,
+    /// The amount to be delivered by the Escrow.
+    pub amount: CurrencyAmount,
+    /// (Optional) A PREIMAGE-SHA-256 crypto-condition, as hexadecimal, that must be fulfilled to execute the associated EscrowFinish transaction.
+    pub condition: Option<Hex>,
+    /// (Optional) The time, in seconds since the Ripple Epoch, after which this Escrow can be finished. Any EscrowFinish transaction before this time fails.
+    pub finish_after: Option<u32>,
+    /// (Optional) The time, in seconds since the Ripple Epoch, after which this Escrow is considered expired and can be cancelled with an EscrowCancel transaction.
+    pub cancel_after: Option<u32>,
+    /// A bit-map of boolean flags enabled for this Escrow.
+    pub flags: u32,
+    /// A hint indicating which page of the sender's owner directory links to this object, in case the directory consists of multiple pages.
+    pub owner_node: String,
+    /// (Optional) A hint indicating which page of the destination's owner directory links to this object, in case the directory consists of multiple pages.
+    pub destination_node: Option<String>,
+    /// The identifying hash of the transaction that most recently modified this object.
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: H256,
+    /// The index of the ledger that contains the transaction that most recently modified this object.
+    pub previous_txn_lgr_seq: u32,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayChannel {
+    /// The source address that owns this payment channel.
+    pub account: Address,
+    /// The destination address for this payment channel, which can claim XRP from it without signing a transaction.
+    pub destination: Address,
+    /// The total amount of XRP, in drops, that has been allocated to this channel.
+    pub amount: Drops,
+    /// The total amount of XRP, in drops, already paid out by this channel.
+    pub balance: Drops,
+    /// Public key, in hexadecimal, of the key pair that can be used to sign claims against this channel.
+    pub public_key: Hex,
+    /// Number of seconds the source address must wait to close the channel if it still has funds remaining.
+    pub settle_delay: u32,
+    /// (Optional) The mutable expiration time, in seconds since the Ripple Epoch, for this payment channel.
+    pub expiration: Option<u32>,
+    /// (Optional) The immutable expiration time, in seconds since the Ripple Epoch, for this payment channel.
+    pub cancel_after: Option<u32>,
+    /// (Optional) A 32-bit unsigned integer to use as a source tag for payments through this channel.
+    pub source_tag: Option<u32>,
+    /// (Optional) A 32-bit unsigned integer to use as a destination tag for payments through this channel.
+    pub destination_tag: Option<u32>,
+    /// A bit-map of boolean flags enabled for this payment channel.
+    pub flags: u32,
+    /// A hint indicating which page of the source address's owner directory links to this object, in case the directory consists of multiple pages.
+    pub owner_node: String,
+    /// The identifying hash of the transaction that most recently modified this object.
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: H256,
+    /// The index of the ledger that contains the transaction that most recently modified this object.
+    pub previous_txn_lgr_seq: u32,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DirectoryNode {
+    /// A bit-map of boolean flags enabled for this directory.
+    pub flags: u32,
+    /// The hashes of the objects contained in this directory, up to 32 per page.
+    pub indexes: Vec<H256>,
+    /// The index of the root object of this directory.
+    pub root_index: H256,
+    /// (Optional) If this directory consists of multiple pages, the index of the next page.
+    #[serde(rename = "IndexNext")]
+    pub indexes_next: Option<u64>,
+    /// (Optional) If this directory consists of multiple pages, the index of the previous page.
+    #[serde(rename = "IndexPrevious")]
+    pub indexes_prev: Option<u64>,
+    /// (Optional, offer directories only) The address of the account that owns the offers in this directory.
+    pub owner: Option<Address>,
+    /// (Optional, offer directories only) The currency code of the TakerPays amount from the offers in this directory.
+    pub taker_pays_currency: Option<String>,
+    /// (Optional, offer directories only) The issuer of the TakerPays amount from the offers in this directory.
+    pub taker_pays_issuer: Option<Address>,
+    /// (Optional, offer directories only) The currency code of the TakerGets amount from the offers in this directory.
+    pub taker_gets_currency: Option<String>,
+    /// (Optional, offer directories only) The issuer of the TakerGets amount from the offers in this directory.
+    pub taker_gets_issuer: Option<Address>,
+}
+
+/// A `SignerList` ledger object. Distinct from [`SignerList`], which models
+/// the inner list carried by a `SignerListSet` transaction.
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SignerListEntry {
+    /// A bit-map of boolean flags enabled for this signer list.
+    pub flags: u32,
+    /// A hint indicating which page of the owner directory links to this object, in case the directory consists of multiple pages.
+    pub owner_node: String,
+    /// The identifying hash of the transaction that most recently modified this object.
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: H256,
+    /// The index of the ledger that contains the transaction that most recently modified this object.
+    pub previous_txn_lgr_seq: u32,
+    /// An array of SignerEntry objects representing the parties who are part of this signer list.
+    #[serde(rename = "SignerEntries")]
+    pub signer_entries: Vec<SignerEntry>,
+    /// A target number of signer weights that must be met or exceeded to authorize a transaction.
+    #[serde(rename = "SignerQuorum")]
+    pub signer_quorum: u32,
+    /// (Optional) An arbitrary 32-bit integer used to identify the signer list, if an account has more than one.
+    #[serde(rename = "SignerListID")]
+    pub signer_list_id: Option<u32>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Ticket {
+    /// The account that owns this Ticket.
+    pub account: Address,
+    /// A bit-map of boolean flags enabled for this Ticket.
+    pub flags: u32,
+    /// The Sequence Number this Ticket sets aside.
+    pub ticket_sequence: u32,
+    /// A hint indicating which page of the owner directory links to this object, in case the directory consists of multiple pages.
+    pub owner_node: String,
+    /// The identifying hash of the transaction that most recently modified this object.
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: H256,
+    /// The index of the ledger that contains the transaction that most recently modified this object.
+    pub previous_txn_lgr_seq: u32,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DepositPreauth {
+    /// The account that provided the preauthorization.
+    pub account: Address,
+    /// The account that received the preauthorization.
+    pub authorize: Address,
+    /// A bit-map of boolean flags enabled for this object.
+    pub flags: u32,
+    /// A hint indicating which page of the owner directory links to this object, in case the directory consists of multiple pages.
+    pub owner_node: String,
+    /// The identifying hash of the transaction that most recently modified this object.
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: H256,
+    /// The index of the ledger that contains the transaction that most recently modified this object.
+    pub previous_txn_lgr_seq: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_new_accepts_max_supply() {
+        assert!(Drops::new(Drops::MAX.0).is_ok());
+    }
+
+    #[test]
+    fn drops_new_rejects_above_max_supply() {
+        assert_eq!(
+            Drops::new(Drops::MAX.0 + 1),
+            Err(AmountError::ExceedsMaxSupply {
+                value: Drops::MAX.0 + 1,
+                max: Drops::MAX.0,
+            })
+        );
+    }
+
+    #[test]
+    fn drops_from_xrp_rejects_negative() {
+        assert_eq!(Drops::from_xrp(-1.0), Err(AmountError::NegativeAmount));
+    }
+
+    #[test]
+    fn drops_from_xrp_rejects_above_max_supply() {
+        assert!(Drops::from_xrp(100_000_000_001.0).is_err());
+    }
+
+    #[test]
+    fn drops_checked_add_rejects_above_max_supply() {
+        assert_eq!(Drops::MAX.checked_add(Drops(1)), None);
+    }
+
+    #[test]
+    fn drops_try_from_str_enforces_max_supply() {
+        // One drop above Drops::MAX.
+        assert_eq!(
+            Drops::try_from("100000000000000001"),
+            Err(DropsParseError::Amount(AmountError::ExceedsMaxSupply {
+                value: 100_000_000_000_000_001,
+                max: Drops::MAX.0,
+            }))
+        );
+    }
+
+    #[test]
+    fn drops_try_from_string_enforces_max_supply() {
+        assert!(Drops::try_from("100000000000000001".to_string()).is_err());
+    }
+
+    #[test]
+    fn drops_try_from_str_rejects_non_integer() {
+        assert!(matches!(
+            Drops::try_from("not a number"),
+            Err(DropsParseError::InvalidInt(_))
+        ));
+    }
+
+    #[test]
+    fn ledger_entry_falls_back_to_unknown_for_unrecognized_type() {
+        let json = serde_json::json!({
+            "LedgerEntryType": "NFTokenPage",
+            "PreviousTxnLgrSeq": 1,
+        });
+        let entry: LedgerEntry = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(entry, LedgerEntry::Unknown(json));
+    }
+
+    #[test]
+    fn ledger_entry_unknown_round_trips_losslessly() {
+        let json = serde_json::json!({
+            "LedgerEntryType": "NFTokenPage",
+            "PreviousTxnLgrSeq": 1,
+        });
+        let entry: LedgerEntry = serde_json::from_value(json.clone()).unwrap();
+        let reserialized = serde_json::to_value(&entry).unwrap();
+        assert_eq!(reserialized, json);
+    }
+
+    #[test]
+    fn ledger_entry_known_variant_round_trips_with_tag() {
+        let json = serde_json::json!({
+            "LedgerEntryType": "Ticket",
+            "Account": "rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+            "Flags": 0,
+            "TicketSequence": 1,
+            "OwnerNode": "0",
+            "PreviousTxnID": "0".repeat(64),
+            "PreviousTxnLgrSeq": 1,
+        });
+        let entry: LedgerEntry = serde_json::from_value(json.clone()).unwrap();
+        let reserialized = serde_json::to_value(&entry).unwrap();
+        assert_eq!(reserialized, json);
+    }
+}